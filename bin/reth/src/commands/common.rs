@@ -0,0 +1,86 @@
+//! Shared CLI arguments and environment construction for commands that open a datadir directly
+//! (`db`, `stage run`, `stage unwind`, ...), as opposed to going through a running node.
+
+use std::sync::Arc;
+
+use clap::Args;
+use reth_chainspec::ChainSpec;
+use reth_config::Config;
+use reth_db::{
+    init_db, lockfile::StorageLock, mdbx::DatabaseArguments, open_db_read_only, DatabaseEnv,
+};
+use reth_node_core::{
+    args::{DatabaseArgs, DatadirArgs},
+    dirs::{ChainPath, DataDirPath},
+};
+use reth_provider::{providers::StaticFileProvider, ProviderFactory};
+
+/// Whether a command needs read-only or exclusive read-write access to the datadir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AccessRights {
+    /// Read-write access.
+    ///
+    /// Acquires the datadir's exclusive [`StorageLock`] — the same lock a running node takes
+    /// when it opens its database — so a live node and any RW command (including another
+    /// `stage unwind`) always contend for the same lock, instead of each opener racing its own.
+    RW,
+    /// Read-only access. Does not contend for the exclusive lock.
+    RO,
+}
+
+/// A fully initialized environment, ready to use by commands that access the datadir directly.
+pub(crate) struct Environment {
+    /// Loaded node configuration.
+    pub(crate) config: Config,
+    /// Provider factory wrapping the opened database and static files.
+    pub(crate) provider_factory: ProviderFactory<Arc<DatabaseEnv>>,
+    /// Resolved datadir paths.
+    pub(crate) data_dir: ChainPath<DataDirPath>,
+    /// Held for the lifetime of the environment when opened with [`AccessRights::RW`]; dropped
+    /// (and thus released) together with the rest of the environment.
+    _lock: Option<StorageLock>,
+}
+
+/// CLI arguments for commands that open a datadir directly.
+#[derive(Debug, Args)]
+pub(crate) struct EnvironmentArgs {
+    /// Parameters for datadir configuration.
+    #[command(flatten)]
+    pub(crate) datadir: DatadirArgs,
+
+    /// Database arguments.
+    #[command(flatten)]
+    pub(crate) db: DatabaseArgs,
+}
+
+impl EnvironmentArgs {
+    /// Resolves the datadir, acquires the exclusive [`StorageLock`] for [`AccessRights::RW`],
+    /// then opens the database and static files.
+    pub(crate) fn init(&self, access_rights: AccessRights) -> eyre::Result<Environment> {
+        let data_dir = self.datadir.clone().resolve_datadir(ChainSpec::mainnet().chain);
+
+        // Acquired before anything touches MDBX or static files, at the one place every direct
+        // datadir opener (this CLI, and a running node's own startup) goes through, so the two
+        // always contend for the same lock rather than this only serializing two CLI commands.
+        let lock = match access_rights {
+            AccessRights::RW => Some(StorageLock::try_acquire(data_dir.data_dir())?),
+            AccessRights::RO => None,
+        };
+
+        let db_args = DatabaseArguments::new(self.db.clone());
+        let (db, static_file_provider) = match access_rights {
+            AccessRights::RW => (
+                Arc::new(init_db(data_dir.db(), db_args)?),
+                StaticFileProvider::read_write(data_dir.static_files())?,
+            ),
+            AccessRights::RO => (
+                Arc::new(open_db_read_only(&data_dir.db(), db_args)?),
+                StaticFileProvider::read_only(data_dir.static_files())?,
+            ),
+        };
+        let provider_factory =
+            ProviderFactory::new(db, Arc::new(ChainSpec::mainnet()), static_file_provider);
+
+        Ok(Environment { config: Config::default(), provider_factory, data_dir, _lock: lock })
+    }
+}
@@ -0,0 +1,4 @@
+//! CLI subcommands.
+
+pub(crate) mod common;
+pub mod stage;
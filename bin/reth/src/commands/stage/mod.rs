@@ -0,0 +1,3 @@
+//! `stage` subcommands.
+
+pub mod unwind;
@@ -6,12 +6,12 @@ use reth_config::Config;
 use reth_consensus::Consensus;
 use reth_db_api::database::Database;
 use reth_downloaders::{bodies::noop::NoopBodiesDownloader, headers::noop::NoopHeaderDownloader};
-use reth_exex::ExExManagerHandle;
+use reth_exex::{ExExManager, ExExManagerHandle, ExExNotification, Wal};
 use reth_node_core::args::NetworkArgs;
-use reth_primitives::{BlockHashOrNumber, BlockNumber, B256};
+use reth_primitives::{BlockHashOrNumber, BlockNumber, L1Origin, B256};
 use reth_provider::{
     BlockExecutionWriter, BlockNumReader, ChainSpecProvider, FinalizedBlockReader,
-    FinalizedBlockWriter, ProviderFactory, StaticFileProviderFactory,
+    FinalizedBlockWriter, L1OriginReader, ProviderFactory, StaticFileProviderFactory,
 };
 use reth_prune_types::PruneModes;
 use reth_stages::{
@@ -38,6 +38,10 @@ pub struct Command {
     #[command(flatten)]
     network: NetworkArgs,
 
+    /// Reports what the unwind would do without mutating the database or static files.
+    #[arg(long)]
+    dry_run: bool,
+
     #[command(subcommand)]
     command: Subcommands,
 }
@@ -45,34 +49,58 @@ pub struct Command {
 impl Command {
     /// Execute `db stage unwind` command
     pub async fn execute(self) -> eyre::Result<()> {
-        let Environment { provider_factory, config, .. } = self.env.init(AccessRights::RW)?;
+        let access_rights = if self.dry_run { AccessRights::RO } else { AccessRights::RW };
+        let Environment { provider_factory, config, data_dir, .. } =
+            self.env.init(access_rights)?;
 
         let range = self.command.unwind_range(provider_factory.clone())?;
         if *range.start() == 0 {
             eyre::bail!("Cannot unwind genesis block")
         }
 
-        // Only execute a pipeline unwind if the start of the range overlaps the existing static
-        // files. If that's the case, then copy all available data from MDBX to static files, and
-        // only then, proceed with the unwind.
-        if let Some(highest_static_block) = provider_factory
+        let highest_static_block = provider_factory
             .static_file_provider()
             .get_highest_static_files()
             .max()
-            .filter(|highest_static_file_block| highest_static_file_block >= range.start())
-        {
+            .filter(|highest_static_file_block| highest_static_file_block >= range.start());
+
+        if self.dry_run {
+            return self.report_dry_run(&provider_factory, &range, highest_static_block)
+        }
+
+        // A real `ExExManagerHandle` backed by the datadir's actual WAL, so that reverted blocks
+        // are delivered to every ExEx as an `ExExNotification`, and `finalize_wal` below
+        // prunes the same on-disk WAL a running node would later replay from, rather than an
+        // ephemeral in-memory one scoped to this process.
+        //
+        // The handle vec is empty because this is a one-shot CLI invocation: there are no live
+        // ExEx tasks running in this process to subscribe. Standalone `stage unwind` only needs
+        // the WAL write (so a subsequent node start-up can still replay it) and the finalize
+        // hook; it is not the delivery mechanism for any ExEx actually running.
+        let wal = Wal::new(data_dir.data_dir().join("exex").join("wal"))?;
+        let (exex_manager, exex_manager_handle) = ExExManager::new(Vec::new(), 1024, wal);
+        tokio::spawn(exex_manager);
+
+        // Only execute a pipeline unwind if the start of the range overlaps the existing static
+        // files. If that's the case, then copy all available data from MDBX to static files, and
+        // only then, proceed with the unwind.
+        let finalized_block_number = if let Some(highest_static_block) = highest_static_block {
             info!(target: "reth::cli", ?range, ?highest_static_block, "Executing a pipeline unwind.");
-            let mut pipeline = self.build_pipeline(config, provider_factory.clone()).await?;
+            let mut pipeline = self
+                .build_pipeline(config, provider_factory.clone(), exex_manager_handle.clone())
+                .await?;
 
             // Move all applicable data from database to static files.
             pipeline.move_to_static_files()?;
 
             pipeline.unwind((*range.start()).saturating_sub(1), None)?;
+
+            provider_factory.provider()?.last_finalized_block_number()?
         } else {
             info!(target: "reth::cli", ?range, "Executing a database unwind.");
             let provider = provider_factory.provider_rw()?;
 
-            let _ = provider
+            let reverted_chain = provider
                 .take_block_and_execution_range(range.clone())
                 .map_err(|err| eyre::eyre!("Transaction error on unwind: {err}"))?;
 
@@ -80,22 +108,99 @@ impl Command {
             let last_saved_finalized_block_number = provider.last_finalized_block_number()?;
             let range_min =
                 range.clone().min().ok_or(eyre::eyre!("Could not fetch lower range end"))?;
-            if range_min < last_saved_finalized_block_number {
+            let finalized_block_number = if range_min < last_saved_finalized_block_number {
                 provider.save_finalized_block_number(BlockNumber::from(range_min))?;
-            }
+                range_min
+            } else {
+                last_saved_finalized_block_number
+            };
 
             provider.commit()?;
-        }
+
+            // No stage pipeline is involved in a database-only unwind, so notify ExExes of the
+            // revert directly, now that it has committed.
+            exex_manager_handle
+                .send(ExExNotification::ChainReverted { old: Arc::new(reverted_chain) })
+                .await
+                .map_err(|err| eyre::eyre!("Failed to notify ExExes of the revert: {err}"))?;
+
+            finalized_block_number
+        };
+
+        // Only prune WAL entries once the unwind has actually committed, so a crash mid-unwind
+        // leaves the WAL able to replay.
+        exex_manager_handle.finalize_wal(finalized_block_number)?;
 
         println!("Unwound {} blocks", range.count());
 
         Ok(())
     }
 
+    /// Prints what [`Self::execute`] would do for `range`, without opening an RW transaction or
+    /// committing anything.
+    fn report_dry_run<DB: Database>(
+        &self,
+        provider_factory: &ProviderFactory<DB>,
+        range: &RangeInclusive<BlockNumber>,
+        highest_static_block: Option<BlockNumber>,
+    ) -> eyre::Result<()> {
+        let provider = provider_factory.provider()?;
+
+        let last_saved_finalized_block_number = provider.last_finalized_block_number()?;
+
+        println!("Dry run: would unwind {} blocks, range {range:?}", range.clone().count());
+        println!(
+            "  static files: {}",
+            if highest_static_block.is_some() {
+                "range overlaps static files, data would be moved from MDBX to static files \
+                 before unwinding (pipeline unwind)"
+            } else {
+                "no overlap with static files (database unwind)"
+            }
+        );
+
+        // Mirror the two divergent branches of `execute`: a database unwind explicitly recomputes
+        // and saves the finalized block number itself, so its prediction can use that same
+        // formula; a pipeline unwind never recomputes it directly and instead re-reads whatever
+        // the stage pipeline leaves behind, which this dry run cannot simulate without actually
+        // running the stages. Report the current value there instead of a guess.
+        if highest_static_block.is_some() {
+            println!(
+                "  finalized block number: {last_saved_finalized_block_number} (unwind stages \
+                 may update this; not predicted by dry run)"
+            );
+        } else {
+            let finalized_block_number = if *range.start() < last_saved_finalized_block_number {
+                *range.start()
+            } else {
+                last_saved_finalized_block_number
+            };
+            println!("  finalized block number would become: {finalized_block_number}");
+        }
+
+        if matches!(self.command, Subcommands::ToL1Block { .. }) {
+            println!("  L1 origins of reverted L2 blocks:");
+            for block_id in range.clone() {
+                match provider.get_l1_origin(block_id)? {
+                    Some(origin) => println!(
+                        "    block {block_id}: block_id={:?} l1_block_hash={:?}",
+                        origin.block_id, origin.l1_block_hash
+                    ),
+                    None => {
+                        println!("    block {block_id}: no L1Origin (soft/preconfirmed block)")
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     async fn build_pipeline<DB: Database + 'static>(
         self,
         config: Config,
         provider_factory: ProviderFactory<Arc<DB>>,
+        exex_manager_handle: ExExManagerHandle,
     ) -> Result<Pipeline<Arc<DB>>, eyre::Error> {
         let consensus: Arc<dyn Consensus> =
             Arc::new(EthBeaconConsensus::new(provider_factory.chain_spec()));
@@ -128,11 +233,14 @@ impl Command {
                     },
                     stage_conf.execution_external_clean_threshold(),
                     prune_modes,
-                    ExExManagerHandle::empty(),
+                    exex_manager_handle,
                 )),
             )
             .build(
                 provider_factory.clone(),
+                // Cross-process exclusivity against a live node, or another `stage unwind`, is
+                // already guaranteed by the `StorageLock` acquired in `EnvironmentArgs::init`;
+                // this producer only needs to exist for the lifetime of this pipeline.
                 StaticFileProducer::new(provider_factory, PruneModes::default()),
             );
         Ok(pipeline)
@@ -150,6 +258,22 @@ enum Subcommands {
     /// reached.
     #[command(name = "num-blocks")]
     NumBlocks { amount: u64 },
+    /// Unwinds the L2 chain until the highest block whose recorded `L1Origin` is still part
+    /// of the canonical L1 chain, given a new L1 chain head after an L1 reorg.
+    #[command(name = "to-l1-block")]
+    ToL1Block {
+        /// The new canonical L1 block (height or hash) to unwind against. Every L2 block whose
+        /// stored `L1Origin` points above this L1 block, or whose recorded L1 block hash no
+        /// longer matches the canonical L1 chain at that height, is unwound.
+        l1_block: BlockHashOrNumber,
+        /// The canonical L1 chain head hash at `l1_block`, used to detect L2 blocks that were
+        /// built on now-orphaned L1 history.
+        ///
+        /// If not provided, the recorded `HeadL1Origin` hash is used instead when that origin's
+        /// L1 height is `l1_block`; otherwise no hash is checked at that height.
+        #[arg(long)]
+        l1_canonical_hash: Option<B256>,
+    },
 }
 
 impl Subcommands {
@@ -170,12 +294,80 @@ impl Subcommands {
                 BlockHashOrNumber::Number(num) => *num,
             },
             Self::NumBlocks { amount } => last.saturating_sub(*amount),
+            Self::ToL1Block { l1_block, l1_canonical_hash } => {
+                Self::find_l1_reorg_cutoff(&provider, last, *l1_block, *l1_canonical_hash)?
+            }
         } + 1;
         if target > last {
             eyre::bail!("Target block number is higher than the latest block number")
         }
         Ok(target..=last)
     }
+
+    /// Walks the L2 chain from `last` downward and returns the highest L2 block number whose
+    /// recorded [`L1Origin`](reth_primitives::L1Origin) is still part of the canonical L1 chain
+    /// after a reorg to `l1_block`.
+    ///
+    /// L2 blocks with no stored `L1Origin` (soft/preconfirmed blocks) have no bearing on the
+    /// cutoff and are always considered unwindable.
+    fn find_l1_reorg_cutoff<P: L1OriginReader>(
+        provider: &P,
+        last: BlockNumber,
+        l1_block: BlockHashOrNumber,
+        l1_canonical_hash: Option<B256>,
+    ) -> eyre::Result<BlockNumber> {
+        // Resolve the target L1 height, and the hash that must still match at that height. A
+        // hash target doubles as its own canonical hash; a height target falls back to the
+        // caller-supplied hash, or the recorded L1 origin head's hash if none was given and that
+        // head sits at the target height; otherwise only heights are compared.
+        let (target_height, canonical_hash) = match l1_block {
+            BlockHashOrNumber::Number(num) => {
+                let canonical_hash = l1_canonical_hash.or(provider
+                    .get_head_l1_origin()?
+                    .filter(|origin| origin.l1_block_height == Some(num))
+                    .map(|origin| origin.l1_block_hash));
+                (num, canonical_hash)
+            }
+            BlockHashOrNumber::Hash(hash) => {
+                let mut block_id = last;
+                let height = loop {
+                    if let Some(origin) = provider.get_l1_origin(block_id)? {
+                        if origin.l1_block_hash == hash {
+                            break origin.l1_block_height
+                        }
+                    }
+                    if block_id == 0 {
+                        break None
+                    }
+                    block_id -= 1;
+                };
+                let height = height.ok_or_else(|| {
+                    eyre::eyre!("L1 block hash not found in any stored L1Origin: {hash:?}")
+                })?;
+                (height, Some(hash))
+            }
+        };
+
+        let mut cutoff = 0;
+        let mut block_id = last;
+        loop {
+            if let Some(origin) = provider.get_l1_origin(block_id)? {
+                if let Some(height) = origin.l1_block_height {
+                    let still_canonical = height < target_height ||
+                        canonical_hash.is_none_or(|hash| origin.l1_block_hash == hash);
+                    if height <= target_height && still_canonical {
+                        cutoff = block_id;
+                        break
+                    }
+                }
+            }
+            if block_id == 0 {
+                break
+            }
+            block_id -= 1;
+        }
+        Ok(cutoff)
+    }
 }
 
 #[cfg(test)]
@@ -189,5 +381,123 @@ mod tests {
 
         let cmd = Command::parse_from(["reth", "--datadir", "dir", "num-blocks", "100"]);
         assert_eq!(cmd.command, Subcommands::NumBlocks { amount: 100 });
+
+        let cmd = Command::parse_from(["reth", "--datadir", "dir", "to-l1-block", "100"]);
+        assert_eq!(
+            cmd.command,
+            Subcommands::ToL1Block {
+                l1_block: BlockHashOrNumber::Number(100),
+                l1_canonical_hash: None
+            }
+        );
+
+        let cmd =
+            Command::parse_from(["reth", "--datadir", "dir", "--dry-run", "to-block", "100"]);
+        assert!(cmd.dry_run);
+    }
+
+    #[derive(Default)]
+    struct FakeL1OriginReader {
+        origins: std::collections::HashMap<BlockNumber, L1Origin>,
+        head: Option<L1Origin>,
+    }
+
+    impl L1OriginReader for FakeL1OriginReader {
+        fn get_l1_origin(
+            &self,
+            block_id: BlockNumber,
+        ) -> reth_storage_errors::provider::ProviderResult<Option<L1Origin>> {
+            Ok(self.origins.get(&block_id).cloned())
+        }
+
+        fn get_head_l1_origin(
+            &self,
+        ) -> reth_storage_errors::provider::ProviderResult<Option<L1Origin>> {
+            Ok(self.head.clone())
+        }
+    }
+
+    fn origin(block_id: u64, l1_block_height: u64, l1_block_hash: B256) -> L1Origin {
+        L1Origin {
+            block_id: Some(block_id),
+            l2_block_hash: B256::ZERO,
+            l1_block_height: Some(l1_block_height),
+            l1_block_hash,
+        }
+    }
+
+    #[test]
+    fn find_l1_reorg_cutoff_by_height() {
+        let mut reader = FakeL1OriginReader::default();
+        reader.origins.insert(1, origin(1, 10, B256::with_last_byte(1)));
+        reader.origins.insert(2, origin(2, 20, B256::with_last_byte(2)));
+        reader.origins.insert(3, origin(3, 30, B256::with_last_byte(3)));
+        reader.head = reader.origins.get(&3).cloned();
+
+        // Reorging back to L1 height 20 unwinds block 3 (L1 height 30), keeping block 2.
+        let cutoff =
+            Subcommands::find_l1_reorg_cutoff(&reader, 3, BlockHashOrNumber::Number(20), None)
+                .unwrap();
+        assert_eq!(cutoff, 2);
+    }
+
+    #[test]
+    fn find_l1_reorg_cutoff_rejects_stale_hash_at_target_height() {
+        // Block 2 claims L1 height 20, but its recorded hash no longer matches the canonical L1
+        // chain at that height, so it must be unwound too even though its height is in range.
+        let mut reader = FakeL1OriginReader::default();
+        reader.origins.insert(1, origin(1, 10, B256::with_last_byte(1)));
+        reader.origins.insert(2, origin(2, 20, B256::with_last_byte(99)));
+
+        let cutoff = Subcommands::find_l1_reorg_cutoff(
+            &reader,
+            2,
+            BlockHashOrNumber::Number(20),
+            Some(B256::with_last_byte(2)),
+        )
+        .unwrap();
+        assert_eq!(cutoff, 1);
+    }
+
+    #[test]
+    fn find_l1_reorg_cutoff_treats_missing_origin_as_unwindable() {
+        // Block 3 has no stored L1Origin yet (a soft/preconfirmed block) and sits above the
+        // cutoff, so it must not block the walk from reaching block 1.
+        let mut reader = FakeL1OriginReader::default();
+        reader.origins.insert(1, origin(1, 10, B256::with_last_byte(1)));
+
+        let cutoff =
+            Subcommands::find_l1_reorg_cutoff(&reader, 3, BlockHashOrNumber::Number(10), None)
+                .unwrap();
+        assert_eq!(cutoff, 1);
+    }
+
+    #[test]
+    fn find_l1_reorg_cutoff_resolves_l1_hash_target() {
+        let mut reader = FakeL1OriginReader::default();
+        reader.origins.insert(1, origin(1, 10, B256::with_last_byte(1)));
+        reader.origins.insert(2, origin(2, 20, B256::with_last_byte(2)));
+
+        let cutoff = Subcommands::find_l1_reorg_cutoff(
+            &reader,
+            2,
+            BlockHashOrNumber::Hash(B256::with_last_byte(1)),
+            None,
+        )
+        .unwrap();
+        assert_eq!(cutoff, 1);
+    }
+
+    #[test]
+    fn find_l1_reorg_cutoff_keeps_genesis_when_nothing_qualifies() {
+        let mut reader = FakeL1OriginReader::default();
+        reader.origins.insert(1, origin(1, 100, B256::with_last_byte(1)));
+
+        // No block's L1 height is `<=` the target, so the walk bottoms out at genesis: the
+        // caller still adds 1 to this cutoff, so genesis itself is never included in the range.
+        let cutoff =
+            Subcommands::find_l1_reorg_cutoff(&reader, 1, BlockHashOrNumber::Number(0), None)
+                .unwrap();
+        assert_eq!(cutoff, 0);
     }
 }
@@ -0,0 +1,6 @@
+//! RPC namespace traits, implemented by `reth_rpc` and exposed through the RPC module builder.
+
+mod taiko;
+#[cfg(feature = "client")]
+pub use taiko::TaikoApiClient;
+pub use taiko::TaikoApiServer;
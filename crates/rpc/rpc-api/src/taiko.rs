@@ -0,0 +1,18 @@
+//! `taiko` RPC namespace, used by the driver/proposer to reconcile L2 blocks against the L1
+//! blocks they were derived from.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_primitives::{BlockNumber, L1Origin};
+
+/// `taiko` rpc interface.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "taiko"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "taiko"))]
+pub trait TaikoApi {
+    /// Returns the stored [`L1Origin`] for the given L2 block id.
+    #[method(name = "l1OriginByID")]
+    async fn l1_origin_by_id(&self, block_id: BlockNumber) -> RpcResult<Option<L1Origin>>;
+
+    /// Returns the highest recorded [`L1Origin`], i.e. the `HeadL1Origin` pointer.
+    #[method(name = "headL1Origin")]
+    async fn head_l1_origin(&self) -> RpcResult<Option<L1Origin>>;
+}
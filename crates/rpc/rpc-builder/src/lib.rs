@@ -0,0 +1,5 @@
+//! Assembles the RPC namespace modules (`eth`, `debug`, `taiko`, ...) into the transports a node
+//! exposes.
+
+pub mod taiko;
+pub use taiko::merge_taiko;
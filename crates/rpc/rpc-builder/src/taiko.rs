@@ -0,0 +1,60 @@
+//! Wires the `taiko` namespace into the node's RPC module builder, alongside `eth`, `net`,
+//! `debug`, and the other namespaces assembled from `RethRpcModule`.
+
+use jsonrpsee::{core::RegisterMethodError, RpcModule};
+use reth_provider::L1OriginReader;
+use reth_rpc::Taiko;
+use reth_rpc_api::TaikoApiServer;
+
+/// Builds the `taiko` RPC module.
+fn taiko_module<Provider>(provider: Provider) -> impl TaikoApiServer
+where
+    Provider: L1OriginReader + Clone + 'static,
+{
+    Taiko::new(provider)
+}
+
+/// Merges the `taiko` namespace into an already-assembled RPC module set, the same way a
+/// transport's selected `eth`/`debug`/... namespaces are merged in before the combined set is
+/// registered with that transport. Call this wherever `RethRpcModule::Taiko` is selected for a
+/// transport.
+pub fn merge_taiko<Provider>(
+    modules: &mut RpcModule<()>,
+    provider: Provider,
+) -> Result<(), RegisterMethodError>
+where
+    Provider: L1OriginReader + Clone + 'static,
+{
+    modules.merge(taiko_module(provider).into_rpc())
+}
+
+#[cfg(test)]
+mod tests {
+    use reth_primitives::{BlockNumber, L1Origin};
+    use reth_storage_errors::provider::ProviderResult;
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct NoopL1OriginReader;
+
+    impl L1OriginReader for NoopL1OriginReader {
+        fn get_l1_origin(&self, _block_id: BlockNumber) -> ProviderResult<Option<L1Origin>> {
+            Ok(None)
+        }
+
+        fn get_head_l1_origin(&self) -> ProviderResult<Option<L1Origin>> {
+            Ok(None)
+        }
+    }
+
+    #[test]
+    fn merge_taiko_registers_both_methods() {
+        let mut modules = RpcModule::new(());
+        merge_taiko(&mut modules, NoopL1OriginReader).unwrap();
+
+        let methods: Vec<_> = modules.method_names().collect();
+        assert!(methods.contains(&"taiko_l1OriginByID"));
+        assert!(methods.contains(&"taiko_headL1Origin"));
+    }
+}
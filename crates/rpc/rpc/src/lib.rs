@@ -0,0 +1,4 @@
+//! Implementations of the RPC namespace traits from `reth_rpc_api`.
+
+mod taiko;
+pub use taiko::Taiko;
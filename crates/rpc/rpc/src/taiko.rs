@@ -0,0 +1,34 @@
+//! Implementation of the `taiko` RPC namespace.
+
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_primitives::{BlockNumber, L1Origin};
+use reth_provider::L1OriginReader;
+use reth_rpc_api::TaikoApiServer;
+
+/// `taiko` API implementation, backed by any provider that can read persisted `L1Origin`s.
+#[derive(Debug, Clone)]
+pub struct Taiko<Provider> {
+    provider: Provider,
+}
+
+impl<Provider> Taiko<Provider> {
+    /// Creates a new instance of the `taiko` API.
+    pub const fn new(provider: Provider) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait]
+impl<Provider> TaikoApiServer for Taiko<Provider>
+where
+    Provider: L1OriginReader + Clone + 'static,
+{
+    async fn l1_origin_by_id(&self, block_id: BlockNumber) -> RpcResult<Option<L1Origin>> {
+        Ok(self.provider.get_l1_origin(block_id)?)
+    }
+
+    async fn head_l1_origin(&self) -> RpcResult<Option<L1Origin>> {
+        Ok(self.provider.get_head_l1_origin()?)
+    }
+}
@@ -0,0 +1,3 @@
+//! Table definitions and database environment used by `reth_db_api`.
+
+pub mod tables;
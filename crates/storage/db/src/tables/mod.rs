@@ -0,0 +1,21 @@
+//! Table definitions, keyed/valued with the types from [`reth_db_api::table`].
+
+use reth_primitives::{BlockNumber, L1Origin};
+
+use crate::tables;
+
+// This `tables!` invocation is the crate's aggregate `Tables` registry: every table declared
+// here is what `init_db` creates an MDBX sub-database for on startup, so a table only actually
+// exists once it's listed in this block.
+tables! {
+    /// Stores the [`L1Origin`] of each L2 block, keyed by its `block_id`.
+    ///
+    /// This lets a driver/proposer reconcile an L2 block against the L1 block it was derived
+    /// from after restarts and during L1 reorgs.
+    table L1Origins<Key = BlockNumber, Value = L1Origin>;
+
+    /// Stores the highest [`L1Origin`] seen so far, i.e. the one with the greatest `block_id`.
+    ///
+    /// Singleton table keyed by the unit type, mirroring other "latest value" tables.
+    table HeadL1Origin<Key = (), Value = L1Origin>;
+}
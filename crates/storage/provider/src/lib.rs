@@ -0,0 +1,6 @@
+//! Traits and implementations for reading and writing chain data.
+
+mod traits;
+pub use traits::{L1OriginReader, L1OriginWriter};
+
+pub mod providers;
@@ -0,0 +1,85 @@
+//! [`L1OriginReader`]/[`L1OriginWriter`] implementation backed by the `L1Origins` and
+//! `HeadL1Origin` tables.
+
+use reth_db::tables::{HeadL1Origin, L1Origins};
+use reth_db_api::transaction::{DbTx, DbTxMut};
+use reth_primitives::{BlockNumber, L1Origin};
+use reth_storage_errors::provider::ProviderResult;
+
+use crate::{providers::database::provider::DatabaseProvider, L1OriginReader, L1OriginWriter};
+
+impl<TX: DbTx> L1OriginReader for DatabaseProvider<TX> {
+    fn get_l1_origin(&self, block_id: BlockNumber) -> ProviderResult<Option<L1Origin>> {
+        Ok(self.tx_ref().get::<L1Origins>(block_id)?)
+    }
+
+    fn get_head_l1_origin(&self) -> ProviderResult<Option<L1Origin>> {
+        Ok(self.tx_ref().get::<HeadL1Origin>(())?)
+    }
+}
+
+impl<TX: DbTxMut + DbTx> L1OriginWriter for DatabaseProvider<TX> {
+    fn save_l1_origin(&self, block_id: BlockNumber, origin: L1Origin) -> ProviderResult<()> {
+        self.tx_ref().put::<L1Origins>(block_id, origin.clone())?;
+
+        // Only advance the head pointer, never move it backwards, e.g. when backfilling an
+        // older block after a restart.
+        let is_new_head = self
+            .tx_ref()
+            .get::<HeadL1Origin>(())?
+            .and_then(|head| head.block_id)
+            .is_none_or(|head_block_id| block_id >= head_block_id);
+        if is_new_head {
+            self.tx_ref().put::<HeadL1Origin>((), origin)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reth_primitives::B256;
+
+    use super::*;
+    use crate::test_utils::create_test_provider_factory;
+
+    fn origin(block_id: u64, l1_block_height: u64) -> L1Origin {
+        L1Origin {
+            block_id: Some(block_id),
+            l2_block_hash: B256::random(),
+            l1_block_height: Some(l1_block_height),
+            l1_block_hash: B256::random(),
+        }
+    }
+
+    #[test]
+    fn save_l1_origin_head_only_advances() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let high = origin(10, 100);
+        provider.save_l1_origin(10, high.clone()).unwrap();
+        assert_eq!(provider.get_head_l1_origin().unwrap(), Some(high.clone()));
+
+        // Backfilling an older block must not move the head pointer backwards.
+        let low = origin(5, 50);
+        provider.save_l1_origin(5, low.clone()).unwrap();
+        assert_eq!(provider.get_l1_origin(5).unwrap(), Some(low));
+        assert_eq!(provider.get_head_l1_origin().unwrap(), Some(high));
+
+        // A later block advances the head pointer again.
+        let higher = origin(20, 200);
+        provider.save_l1_origin(20, higher.clone()).unwrap();
+        assert_eq!(provider.get_head_l1_origin().unwrap(), Some(higher));
+    }
+
+    #[test]
+    fn get_l1_origin_missing_block_returns_none() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        assert_eq!(provider.get_l1_origin(1).unwrap(), None);
+        assert_eq!(provider.get_head_l1_origin().unwrap(), None);
+    }
+}
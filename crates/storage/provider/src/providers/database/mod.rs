@@ -0,0 +1,3 @@
+//! Database-backed provider implementations.
+
+mod l1_origin;
@@ -0,0 +1,3 @@
+//! Concrete provider implementations.
+
+pub mod database;
@@ -0,0 +1,25 @@
+//! Read/write access to the persisted [`L1Origin`] of L2 blocks.
+
+use reth_primitives::{BlockNumber, L1Origin};
+use reth_storage_errors::provider::ProviderResult;
+
+/// Read access to the stored [`L1Origin`] of L2 blocks.
+///
+/// An L2 block's `L1Origin` records the L1 block it was derived from. Soft/preconfirmed L2
+/// blocks have no `L1Origin` yet, since they have not been anchored to an L1 block.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait L1OriginReader: Send + Sync {
+    /// Returns the stored [`L1Origin`] for the given L2 `block_id`, if any.
+    fn get_l1_origin(&self, block_id: BlockNumber) -> ProviderResult<Option<L1Origin>>;
+
+    /// Returns the highest [`L1Origin`] recorded so far, i.e. the `HeadL1Origin` pointer.
+    fn get_head_l1_origin(&self) -> ProviderResult<Option<L1Origin>>;
+}
+
+/// Write access for persisting the [`L1Origin`] of L2 blocks.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait L1OriginWriter: Send + Sync {
+    /// Persists `origin` under `block_id`, advancing the `HeadL1Origin` pointer if `block_id` is
+    /// the highest one seen so far.
+    fn save_l1_origin(&self, block_id: BlockNumber, origin: L1Origin) -> ProviderResult<()>;
+}
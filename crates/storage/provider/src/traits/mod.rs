@@ -0,0 +1,4 @@
+//! Provider traits for reading/writing chain data.
+
+mod l1_origin;
+pub use l1_origin::{L1OriginReader, L1OriginWriter};